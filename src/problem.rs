@@ -0,0 +1,114 @@
+use std::cmp::Ordering;
+
+use genetic_rs::prelude::*;
+use rand::prelude::*;
+
+use crate::{NeuralNetwork, NeuralNetworkTopology};
+
+/// A problem that a network is evolved to solve.
+///
+/// Implement this instead of re-wiring a [`GeneticSim`] by hand every time: describe the
+/// input/output shape and how to score a runnable [`NeuralNetwork`], then hand the problem
+/// to [`solve`] to run the evolutionary loop and get the best topology back.
+pub trait NeuroProblem<const I: usize, const O: usize>: Send + Sync {
+    /// The number of inputs the network receives. Defaults to the const generic `I`.
+    fn input_count(&self) -> usize {
+        I
+    }
+
+    /// The number of outputs the network produces. Defaults to the const generic `O`.
+    fn output_count(&self) -> usize {
+        O
+    }
+
+    /// Scores a runnable network. Higher is better.
+    fn evaluate(&self, net: &NeuralNetwork<I, O>) -> f32;
+}
+
+/// The genome used by [`solve`]: a thin wrapper around a [`NeuralNetworkTopology`] so the
+/// genetic_rs reproduction traits can be implemented without users re-deriving them.
+#[derive(Debug, Clone)]
+pub struct NeuroGenome<const I: usize, const O: usize> {
+    /// The wrapped topology.
+    pub network: NeuralNetworkTopology<I, O>,
+}
+
+impl<const I: usize, const O: usize> RandomlyMutable for NeuroGenome<I, O> {
+    fn mutate(&mut self, rate: f32, rng: &mut impl Rng) {
+        self.network.mutate(rate, rng);
+    }
+}
+
+impl<const I: usize, const O: usize> DivisionReproduction for NeuroGenome<I, O> {
+    fn spawn_child(&self, rng: &mut impl Rng) -> Self {
+        Self {
+            network: self.network.spawn_child(rng),
+        }
+    }
+}
+
+#[cfg(feature = "crossover")]
+impl<const I: usize, const O: usize> CrossoverReproduction for NeuroGenome<I, O> {
+    fn spawn_child(&self, other: &Self, rng: &mut impl Rng) -> Self {
+        Self {
+            network: self.network.spawn_child(&other.network, rng),
+        }
+    }
+}
+
+impl<const I: usize, const O: usize> Prunable for NeuroGenome<I, O> {}
+
+/// Evolves a solution to `problem` and returns the best topology found.
+///
+/// Builds a [`GeneticSim`] of `population` random topologies (each with the given mutation
+/// settings), advances it for `generations`, and returns the topology with the highest
+/// score under [`NeuroProblem::evaluate`]. The `crossover` feature selects
+/// crossover-based reproduction in place of the default division reproduction.
+///
+/// # Panics
+///
+/// Panics if `population` is `0`, since there is then no topology to return.
+pub fn solve<const I: usize, const O: usize>(
+    problem: &(impl NeuroProblem<I, O> + Clone + 'static),
+    population: usize,
+    generations: usize,
+    mutation_rate: f32,
+    mutation_passes: usize,
+    rng: &mut impl Rng,
+) -> NeuralNetworkTopology<I, O> {
+    assert!(population > 0, "population must be non-zero");
+
+    let genomes: Vec<NeuroGenome<I, O>> = (0..population)
+        .map(|_| NeuroGenome {
+            network: NeuralNetworkTopology::new(mutation_rate, mutation_passes, rng),
+        })
+        .collect();
+
+    let fitness = {
+        let problem = problem.clone();
+        move |g: &NeuroGenome<I, O>| problem.evaluate(&(&g.network).into())
+    };
+
+    let mut sim = GeneticSim::new(
+        genomes,
+        fitness.clone(),
+        #[cfg(not(feature = "crossover"))]
+        division_pruning_nextgen,
+        #[cfg(feature = "crossover")]
+        crossover_pruning_nextgen,
+    );
+
+    for _ in 0..generations {
+        sim.next_generation();
+    }
+
+    sim.genomes
+        .into_iter()
+        .max_by(|a, b| {
+            fitness(a)
+                .partial_cmp(&fitness(b))
+                .unwrap_or(Ordering::Equal)
+        })
+        .unwrap()
+        .network
+}
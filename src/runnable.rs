@@ -0,0 +1,175 @@
+use std::sync::RwLock;
+
+use crate::topology::{ActivationFn, NeuralNetworkTopology, NeuronLocation};
+
+/// A runnable neural network, built from a stateless [`NeuralNetworkTopology`] via [`From`].
+/// Unlike the topology this owns plain neurons with cached activation state, so it can be
+/// evaluated cheaply with [`NeuralNetwork::predict`].
+#[derive(Debug)]
+pub struct NeuralNetwork<const I: usize, const O: usize> {
+    input_layer: [Neuron; I],
+    hidden_layers: Vec<Neuron>,
+    output_layer: [Neuron; O],
+
+    /// Whether this network contains recurrent (cyclic) connections. When `true`, a
+    /// connection that closes a cycle reads its source neuron's activation from the
+    /// previous [`NeuralNetwork::predict`] call rather than recursing within the current pass.
+    recurrent: bool,
+}
+
+impl<const I: usize, const O: usize> NeuralNetwork<I, O> {
+    /// Runs the network over `inputs`, returning the activation of each output neuron.
+    ///
+    /// For recurrent networks the per-neuron state persists across calls: a recurrent edge
+    /// reads the source neuron's activation from the *previous* `predict` call. Use
+    /// [`NeuralNetwork::reset_state`] to clear this memory between independent sequences.
+    pub fn predict(&self, inputs: [f32; I]) -> [f32; O] {
+        // Roll the current activations into the previous-pass buffer so recurrent edges
+        // read last call's values, then clear the per-pass processing flags.
+        for n in self.neurons() {
+            let mut state = n.state.write().unwrap();
+            if self.recurrent {
+                state.prev_value = state.value;
+            }
+            state.processed = false;
+            state.processing = false;
+        }
+
+        // Seed the input layer.
+        for (i, v) in inputs.iter().enumerate() {
+            let mut state = self.input_layer[i].state.write().unwrap();
+            state.value = *v;
+            state.processed = true;
+        }
+
+        (0..O)
+            .map(|i| self.process_neuron(NeuronLocation::Output(i)))
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    /// Clears all persistent per-neuron state, so the next [`NeuralNetwork::predict`] starts
+    /// with no recurrent memory. Call this between independent input sequences.
+    pub fn reset_state(&self) {
+        for n in self.neurons() {
+            *n.state.write().unwrap() = NeuronState::default();
+        }
+    }
+
+    /// Recursively evaluates the neuron at `loc`, memoizing the result in its state.
+    /// A neuron encountered while it is already being processed indicates a recurrent edge;
+    /// its previous-pass activation is returned instead of recursing, which also prevents
+    /// infinite recursion on cyclic topologies.
+    fn process_neuron(&self, loc: NeuronLocation) -> f32 {
+        let neuron = self.get_neuron(loc);
+
+        {
+            let state = neuron.state.read().unwrap();
+            if state.processed {
+                return state.value;
+            }
+            if state.processing {
+                return state.prev_value;
+            }
+        }
+
+        neuron.state.write().unwrap().processing = true;
+
+        let mut sum = neuron.bias;
+        for &(input, weight) in &neuron.inputs {
+            sum += self.process_neuron(input) * weight;
+        }
+
+        let value = neuron.activation.apply(sum);
+
+        let mut state = neuron.state.write().unwrap();
+        state.value = value;
+        state.processed = true;
+        state.processing = false;
+
+        value
+    }
+
+    /// Iterates over every neuron, in input → hidden → output order.
+    fn neurons(&self) -> impl Iterator<Item = &Neuron> {
+        self.input_layer
+            .iter()
+            .chain(self.hidden_layers.iter())
+            .chain(self.output_layer.iter())
+    }
+
+    /// Gets a neuron from a [`NeuronLocation`].
+    fn get_neuron(&self, loc: NeuronLocation) -> &Neuron {
+        match loc {
+            NeuronLocation::Input(i) => &self.input_layer[i],
+            NeuronLocation::Hidden(i) => &self.hidden_layers[i],
+            NeuronLocation::Output(i) => &self.output_layer[i],
+        }
+    }
+}
+
+impl<const I: usize, const O: usize> From<&NeuralNetworkTopology<I, O>> for NeuralNetwork<I, O> {
+    fn from(topology: &NeuralNetworkTopology<I, O>) -> Self {
+        let map = |n: &std::sync::Arc<RwLock<crate::topology::NeuronTopology>>| Neuron::from(&*n.read().unwrap());
+
+        let input_layer = topology
+            .input_layer
+            .iter()
+            .map(map)
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        let output_layer = topology
+            .output_layer
+            .iter()
+            .map(map)
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+
+        Self {
+            input_layer,
+            hidden_layers: topology.hidden_layers.iter().map(map).collect(),
+            output_layer,
+            recurrent: topology.recurrent,
+        }
+    }
+}
+
+/// A runnable neuron with cached activation state.
+#[derive(Debug)]
+pub struct Neuron {
+    inputs: Vec<(NeuronLocation, f32)>,
+    bias: f32,
+    activation: ActivationFn,
+    state: RwLock<NeuronState>,
+}
+
+impl From<&crate::topology::NeuronTopology> for Neuron {
+    fn from(n: &crate::topology::NeuronTopology) -> Self {
+        Self {
+            inputs: n.inputs.iter().map(|&(loc, w, _inv)| (loc, w)).collect(),
+            bias: n.bias,
+            activation: n.activation,
+            state: RwLock::new(NeuronState::default()),
+        }
+    }
+}
+
+/// The mutable per-evaluation state of a [`Neuron`].
+#[derive(Debug, Default)]
+struct NeuronState {
+    /// This pass's activation.
+    value: f32,
+
+    /// The activation from the previous `predict` call, read by recurrent edges.
+    prev_value: f32,
+
+    /// Whether `value` has been computed this pass.
+    processed: bool,
+
+    /// Whether this neuron is currently being evaluated (used to detect recurrent edges).
+    processing: bool,
+}
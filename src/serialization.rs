@@ -0,0 +1,289 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::topology::{ActivationFn, NeuralNetworkTopology, NeuronLocation, NeuronTopology, WeightInitializer};
+
+/// The current format version written by [`PortableTopology`]. Bumped whenever the
+/// on-disk layout changes in a backwards-incompatible way.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// A single neuron as stored in a [`PortableTopology`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortableNeuron {
+    /// Incoming connections as `(source, weight, innovation)` triples.
+    pub inputs: Vec<(NeuronLocation, f32, usize)>,
+
+    /// The neuron's bias.
+    pub bias: f32,
+
+    /// The neuron's activation function.
+    pub activation: ActivationFn,
+}
+
+impl From<&NeuronTopology> for PortableNeuron {
+    fn from(n: &NeuronTopology) -> Self {
+        Self {
+            inputs: n.inputs.clone(),
+            bias: n.bias,
+            activation: n.activation,
+        }
+    }
+}
+
+impl From<&PortableNeuron> for NeuronTopology {
+    fn from(n: &PortableNeuron) -> Self {
+        Self {
+            inputs: n.inputs.clone(),
+            bias: n.bias,
+            activation: n.activation,
+        }
+    }
+}
+
+/// A portable, versioned encoding of a [`NeuralNetworkTopology`].
+///
+/// Unlike a raw structural dump this records a format version, the `I`/`O` dimensions,
+/// every neuron's activation function, the recurrent flag, and optional free-form user
+/// metadata, so trained topologies can be persisted and reloaded across crate versions
+/// and shared between projects. Convert with [`PortableTopology::from`] and back with the
+/// validating [`TryFrom`] impl.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortableTopology {
+    /// The format version this blob was written with.
+    pub version: u32,
+
+    /// The number of input neurons (must match the target `I` on load).
+    pub input_count: usize,
+
+    /// The number of output neurons (must match the target `O` on load).
+    pub output_count: usize,
+
+    /// The mutation rate carried by the topology.
+    pub mutation_rate: f32,
+
+    /// The number of mutation passes carried by the topology.
+    pub mutation_passes: usize,
+
+    /// Whether the topology evolves recurrent connections.
+    pub recurrent: bool,
+
+    /// The weight initializer used for new connections.
+    pub weight_init: WeightInitializer,
+
+    /// The input layer neurons.
+    pub input_layer: Vec<PortableNeuron>,
+
+    /// The flat pool of hidden neurons.
+    pub hidden_layers: Vec<PortableNeuron>,
+
+    /// The output layer neurons.
+    pub output_layer: Vec<PortableNeuron>,
+
+    /// Optional free-form user metadata (e.g. a training description or provenance note).
+    pub metadata: Option<String>,
+}
+
+impl<const I: usize, const O: usize> From<&NeuralNetworkTopology<I, O>> for PortableTopology {
+    fn from(net: &NeuralNetworkTopology<I, O>) -> Self {
+        let map = |layer: &[Arc<RwLock<NeuronTopology>>]| {
+            layer
+                .iter()
+                .map(|n| PortableNeuron::from(&*n.read().unwrap()))
+                .collect()
+        };
+
+        Self {
+            version: FORMAT_VERSION,
+            input_count: I,
+            output_count: O,
+            mutation_rate: net.mutation_rate,
+            mutation_passes: net.mutation_passes,
+            recurrent: net.recurrent,
+            weight_init: net.weight_init,
+            input_layer: map(&net.input_layer),
+            hidden_layers: map(&net.hidden_layers),
+            output_layer: map(&net.output_layer),
+            metadata: None,
+        }
+    }
+}
+
+impl PortableTopology {
+    /// Attaches user metadata, returning `self` for chaining.
+    pub fn with_metadata(mut self, metadata: impl Into<String>) -> Self {
+        self.metadata = Some(metadata.into());
+        self
+    }
+
+    /// Serializes to pretty JSON and writes it to `path`.
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), PortableError> {
+        let serialized = serde_json::to_string_pretty(self)?;
+        fs::write(path, serialized)?;
+        Ok(())
+    }
+
+    /// Reads and deserializes a [`PortableTopology`] from `path`.
+    pub fn load_from_file(path: impl AsRef<Path>) -> Result<Self, PortableError> {
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+}
+
+impl<const I: usize, const O: usize> TryFrom<PortableTopology> for NeuralNetworkTopology<I, O> {
+    type Error = PortableError;
+
+    fn try_from(p: PortableTopology) -> Result<Self, Self::Error> {
+        if p.version != FORMAT_VERSION {
+            return Err(PortableError::VersionMismatch {
+                found: p.version,
+                expected: FORMAT_VERSION,
+            });
+        }
+
+        if p.input_count != I || p.output_count != O {
+            return Err(PortableError::DimensionMismatch {
+                found: (p.input_count, p.output_count),
+                expected: (I, O),
+            });
+        }
+
+        let to_layer = |neurons: &[PortableNeuron]| {
+            neurons
+                .iter()
+                .map(|n| Arc::new(RwLock::new(NeuronTopology::from(n))))
+                .collect::<Vec<_>>()
+        };
+
+        let input_layer = to_layer(&p.input_layer)
+            .try_into()
+            .map_err(|_| PortableError::DimensionMismatch {
+                found: (p.input_layer.len(), p.output_layer.len()),
+                expected: (I, O),
+            })?;
+
+        let output_layer = to_layer(&p.output_layer)
+            .try_into()
+            .map_err(|_| PortableError::DimensionMismatch {
+                found: (p.input_layer.len(), p.output_layer.len()),
+                expected: (I, O),
+            })?;
+
+        Ok(Self {
+            input_layer,
+            hidden_layers: to_layer(&p.hidden_layers),
+            output_layer,
+            mutation_rate: p.mutation_rate,
+            mutation_passes: p.mutation_passes,
+            recurrent: p.recurrent,
+            weight_init: p.weight_init,
+        })
+    }
+}
+
+/// Errors produced when encoding, decoding, or validating a [`PortableTopology`].
+#[derive(Debug)]
+pub enum PortableError {
+    /// The blob's format version does not match [`FORMAT_VERSION`].
+    VersionMismatch {
+        /// The version found in the blob.
+        found: u32,
+        /// The version this build expects.
+        expected: u32,
+    },
+
+    /// The blob's `(I, O)` dimensions do not match the target type.
+    DimensionMismatch {
+        /// The `(input, output)` counts found in the blob.
+        found: (usize, usize),
+        /// The `(I, O)` counts the target type expects.
+        expected: (usize, usize),
+    },
+
+    /// An underlying I/O error while reading or writing a file.
+    Io(std::io::Error),
+
+    /// A JSON (de)serialization error.
+    Serde(serde_json::Error),
+}
+
+impl fmt::Display for PortableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::VersionMismatch { found, expected } => write!(
+                f,
+                "format version mismatch: blob is v{found}, this build expects v{expected}"
+            ),
+            Self::DimensionMismatch { found, expected } => write!(
+                f,
+                "dimension mismatch: blob is {found:?}, target expects {expected:?}"
+            ),
+            Self::Io(e) => write!(f, "io error: {e}"),
+            Self::Serde(e) => write!(f, "serialization error: {e}"),
+        }
+    }
+}
+
+impl Error for PortableError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Serde(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for PortableError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for PortableError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Serde(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> NeuralNetworkTopology<2, 3> {
+        let mut rng = rand::thread_rng();
+        NeuralNetworkTopology::new(0.1, 3, &mut rng)
+    }
+
+    #[test]
+    fn json_round_trip_preserves_dimensions() {
+        let net = sample();
+        let portable = PortableTopology::from(&net);
+
+        let json = serde_json::to_string(&portable).unwrap();
+        let decoded: PortableTopology = serde_json::from_str(&json).unwrap();
+
+        let rebuilt: NeuralNetworkTopology<2, 3> = decoded.try_into().unwrap();
+        assert_eq!(rebuilt.input_layer.len(), 2);
+        assert_eq!(rebuilt.output_layer.len(), 3);
+    }
+
+    #[test]
+    fn dimension_mismatch_errors() {
+        let portable = PortableTopology::from(&sample());
+        let result: Result<NeuralNetworkTopology<4, 4>, _> = portable.try_into();
+        assert!(matches!(result, Err(PortableError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn version_mismatch_errors() {
+        let mut portable = PortableTopology::from(&sample());
+        portable.version = FORMAT_VERSION + 1;
+        let result: Result<NeuralNetworkTopology<2, 3>, _> = portable.try_into();
+        assert!(matches!(result, Err(PortableError::VersionMismatch { .. })));
+    }
+}
@@ -1,7 +1,21 @@
+#[cfg(feature = "crossover")]
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 
 use genetic_rs::prelude::*;
 use rand::prelude::*;
+use rand_distr::{Distribution, Normal, Uniform};
+
+/// Global innovation counter, shared across every topology in the process.
+/// Each connection gene is stamped with a value pulled from here when it is
+/// first created, letting [`CrossoverReproduction`] align genes between parents.
+static INNOVATION_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Pulls the next globally-unique innovation number.
+fn next_innovation() -> usize {
+    INNOVATION_COUNTER.fetch_add(1, Ordering::Relaxed)
+}
 
 /// A stateless neural network topology.
 /// This is the struct you want to use in your agent's inheritance.
@@ -22,13 +36,76 @@ pub struct NeuralNetworkTopology<const I: usize, const O: usize> {
 
     /// The number of mutation passes (and thus, maximum number of possible mutations that can occur for each entity in the generation).
     pub mutation_passes: usize,
+
+    /// When `true`, the add-connection mutation is allowed to create cyclic (recurrent)
+    /// connections. Recurrent edges are evaluated from the previous `predict` pass's
+    /// activations in the runnable [`NeuralNetwork`][crate::NeuralNetwork], letting the
+    /// topology evolve memory for sequential/temporal tasks. Defaults to `false`.
+    pub recurrent: bool,
+
+    /// The distribution used to draw new weights and biases, both at construction
+    /// and whenever a mutation creates a fresh connection.
+    pub weight_init: WeightInitializer,
+}
+
+/// Strategy for drawing the initial value of a weight or bias.
+///
+/// `gen::<f32>()` only yields values in `[0, 1)`, which is biased and poor for
+/// training; these distributions produce zero-centered weights instead.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WeightInitializer {
+    /// Uniform over `[-1, 1]`.
+    UniformSymmetric,
+
+    /// Standard Gaussian, `N(0, 1)`.
+    Gaussian,
+
+    /// He initialization, `N(0, sqrt(2 / fan_in))`, suited to ReLU-style activations.
+    He,
+}
+
+impl Default for WeightInitializer {
+    fn default() -> Self {
+        Self::UniformSymmetric
+    }
+}
+
+impl WeightInitializer {
+    /// Samples a single value for a neuron with the given `fan_in` (number of incoming
+    /// connections). `fan_in` only affects [`WeightInitializer::He`].
+    pub fn sample(&self, rng: &mut impl Rng, fan_in: usize) -> f32 {
+        match self {
+            Self::UniformSymmetric => Uniform::new_inclusive(-1., 1.).sample(rng),
+            Self::Gaussian => Normal::new(0., 1.).unwrap().sample(rng),
+            Self::He => {
+                let std = (2. / fan_in.max(1) as f32).sqrt();
+                Normal::new(0., std).unwrap().sample(rng)
+            }
+        }
+    }
 }
 
 impl<const I: usize, const O: usize> NeuralNetworkTopology<I, O> {
-    /// Creates a new [`NeuralNetworkTopology`].
+    /// Creates a new [`NeuralNetworkTopology`] using the default [`WeightInitializer`].
     pub fn new(mutation_rate: f32, mutation_passes: usize, rng: &mut impl Rng) -> Self {
+        Self::new_with_init(
+            mutation_rate,
+            mutation_passes,
+            WeightInitializer::default(),
+            rng,
+        )
+    }
+
+    /// Creates a new [`NeuralNetworkTopology`] with an explicit [`WeightInitializer`].
+    pub fn new_with_init(
+        mutation_rate: f32,
+        mutation_passes: usize,
+        weight_init: WeightInitializer,
+        rng: &mut impl Rng,
+    ) -> Self {
         let input_layer: [Arc<RwLock<NeuronTopology>>; I] = (0..I)
-            .map(|_| Arc::new(RwLock::new(NeuronTopology::new(vec![], rng))))
+            .map(|_| Arc::new(RwLock::new(NeuronTopology::new(vec![], weight_init, rng))))
             .collect::<Vec<_>>()
             .try_into()
             .unwrap();
@@ -51,7 +128,11 @@ impl<const I: usize, const O: usize> NeuralNetworkTopology<I, O> {
                 })
                 .collect();
 
-            output_layer.push(Arc::new(RwLock::new(NeuronTopology::new(input, rng))));
+            output_layer.push(Arc::new(RwLock::new(NeuronTopology::new(
+                input,
+                weight_init,
+                rng,
+            ))));
         }
 
         let output_layer = output_layer.try_into().unwrap();
@@ -62,15 +143,24 @@ impl<const I: usize, const O: usize> NeuralNetworkTopology<I, O> {
             output_layer,
             mutation_rate,
             mutation_passes,
+            recurrent: false,
+            weight_init,
         }
     }
 
+    /// Enables recurrent mode, allowing the add-connection mutation to form cycles.
+    /// Returns `self` so it can be chained after [`NeuralNetworkTopology::new`].
+    pub fn with_recurrent(mut self, recurrent: bool) -> Self {
+        self.recurrent = recurrent;
+        self
+    }
+
     fn is_connection_cyclic(&self, loc1: NeuronLocation, loc2: NeuronLocation) -> bool {
         if loc1 == loc2 {
             return true;
         }
 
-        for &(n, _w) in &self.get_neuron(loc1).read().unwrap().inputs {
+        for &(n, _w, _inv) in &self.get_neuron(loc1).read().unwrap().inputs {
             if self.is_connection_cyclic(n, loc2) {
                 return true;
             }
@@ -89,6 +179,63 @@ impl<const I: usize, const O: usize> NeuralNetworkTopology<I, O> {
         }
     }
 
+    /// Fills `genes` with this topology's connections keyed by innovation number,
+    /// mapping each to its weight. Used to align genes during crossover.
+    #[cfg(feature = "crossover")]
+    fn collect_genes(&self, genes: &mut HashMap<usize, f32>) {
+        for n in self
+            .input_layer
+            .iter()
+            .chain(self.hidden_layers.iter())
+            .chain(self.output_layer.iter())
+        {
+            for (_, w, inv) in &n.read().unwrap().inputs {
+                genes.insert(*inv, *w);
+            }
+        }
+    }
+
+    /// Returns every neuron paired with its [`NeuronLocation`], in input → hidden → output order.
+    /// This takes a read snapshot of each neuron, so the returned views are independent of the
+    /// live `Arc<RwLock<_>>` fields and safe to hold without locking.
+    pub fn neurons(&self) -> impl Iterator<Item = (NeuronLocation, NeuronTopologyView)> {
+        self.located_neurons()
+            .into_iter()
+            .map(|(loc, n)| (loc, NeuronTopologyView::new(loc, &n.read().unwrap())))
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// Returns every connection as `(src, dst, weight)`, where `dst` is the neuron the
+    /// connection feeds into and `src` is where its input comes from. Useful for exporting to
+    /// DOT/Graphviz, counting connections for complexity penalties, or custom mutation operators.
+    pub fn connections(&self) -> impl Iterator<Item = (NeuronLocation, NeuronLocation, f32)> {
+        let mut out = Vec::new();
+        for (dst, n) in self.located_neurons() {
+            for &(src, w, _inv) in &n.read().unwrap().inputs {
+                out.push((src, dst, w));
+            }
+        }
+        out.into_iter()
+    }
+
+    /// Collects every neuron pointer tagged with its location, in layer order.
+    fn located_neurons(&self) -> Vec<(NeuronLocation, Arc<RwLock<NeuronTopology>>)> {
+        let mut out = Vec::with_capacity(I + self.hidden_layers.len() + O);
+
+        for (i, n) in self.input_layer.iter().enumerate() {
+            out.push((NeuronLocation::Input(i), n.clone()));
+        }
+        for (i, n) in self.hidden_layers.iter().enumerate() {
+            out.push((NeuronLocation::Hidden(i), n.clone()));
+        }
+        for (i, n) in self.output_layer.iter().enumerate() {
+            out.push((NeuronLocation::Output(i), n.clone()));
+        }
+
+        out
+    }
+
     /// Gets a random neuron and its location.
     pub fn rand_neuron(&self, rng: &mut impl Rng) -> (Arc<RwLock<NeuronTopology>>, NeuronLocation) {
         match rng.gen_range(0..3) {
@@ -143,6 +290,47 @@ impl<const I: usize, const O: usize> Clone for NeuralNetworkTopology<I, O> {
             output_layer,
             mutation_rate: self.mutation_rate,
             mutation_passes: self.mutation_passes,
+            recurrent: self.recurrent,
+            weight_init: self.weight_init,
+        }
+    }
+}
+
+impl<'a, const I: usize, const O: usize> IntoIterator for &'a NeuralNetworkTopology<I, O> {
+    type Item = (NeuronLocation, NeuronTopologyView);
+    type IntoIter = std::vec::IntoIter<(NeuronLocation, NeuronTopologyView)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.neurons().collect::<Vec<_>>().into_iter()
+    }
+}
+
+/// A read-only snapshot of a [`NeuronTopology`] together with its location, yielded by
+/// [`NeuralNetworkTopology::neurons`]. Detached from the live `RwLock`, so walking a topology
+/// for visualization or analysis needs no manual locking.
+#[derive(Debug, Clone)]
+pub struct NeuronTopologyView {
+    /// Where this neuron lives in the topology.
+    pub location: NeuronLocation,
+
+    /// The incoming connections as `(source, weight, innovation)` triples.
+    pub inputs: Vec<(NeuronLocation, f32, usize)>,
+
+    /// The neuron's bias.
+    pub bias: f32,
+
+    /// The neuron's activation function.
+    pub activation: ActivationFn,
+}
+
+impl NeuronTopologyView {
+    /// Builds a view from a locked neuron and its location.
+    fn new(location: NeuronLocation, neuron: &NeuronTopology) -> Self {
+        Self {
+            location,
+            inputs: neuron.inputs.clone(),
+            bias: neuron.bias,
+            activation: neuron.activation,
         }
     }
 }
@@ -160,13 +348,14 @@ impl<const I: usize, const O: usize> RandomlyMutable for NeuralNetworkTopology<I
 
                 let mut n2 = n2.write().unwrap();
                 let i = rng.gen_range(0..n2.inputs.len());
-                let (loc, w) = n2.inputs.remove(i);
+                let (loc, w, _) = n2.inputs.remove(i);
 
                 let loc3 = NeuronLocation::Hidden(self.hidden_layers.len());
-                self.hidden_layers
-                    .push(Arc::new(RwLock::new(NeuronTopology::new(vec![loc], rng)))); // for some reason, this isn't actually doing anything once it goes to the next scope
+                self.hidden_layers.push(Arc::new(RwLock::new(
+                    NeuronTopology::new(vec![loc], self.weight_init, rng),
+                ))); // for some reason, this isn't actually doing anything once it goes to the next scope
 
-                n2.inputs.insert(i, (loc3, w));
+                n2.inputs.insert(i, (loc3, w, next_innovation()));
             }
 
             if rng.gen::<f32>() <= rate {
@@ -179,11 +368,18 @@ impl<const I: usize, const O: usize> RandomlyMutable for NeuralNetworkTopology<I
 
                 let (mut n2, mut loc2) = self.rand_neuron(rng);
 
-                while self.is_connection_cyclic(loc1, loc2) {
-                    (n2, loc2) = self.rand_neuron(rng);
+                // In feed-forward mode, reject any connection that would close a cycle.
+                // In recurrent mode cycles are permitted — the resulting edge is recurrent
+                // and read from the previous pass's activation at evaluation time.
+                if !self.recurrent {
+                    while self.is_connection_cyclic(loc1, loc2) {
+                        (n2, loc2) = self.rand_neuron(rng);
+                    }
                 }
 
-                n2.write().unwrap().inputs.push((loc1, rng.gen()));
+                let fan_in = n2.read().unwrap().inputs.len() + 1;
+                let w = self.weight_init.sample(rng, fan_in);
+                n2.write().unwrap().inputs.push((loc1, w, next_innovation()));
             }
 
             if rng.gen::<f32>() <= rate {
@@ -195,9 +391,17 @@ impl<const I: usize, const O: usize> RandomlyMutable for NeuralNetworkTopology<I
                 }
 
                 let mut n = n.write().unwrap();
+                let fan_in = n.inputs.len();
                 let i = rng.gen_range(0..n.inputs.len());
-                let (_, w) = &mut n.inputs[i];
-                *w += rng.gen::<f32>() * rate;
+                let delta = self.weight_init.sample(rng, fan_in) * rate;
+                let (_, w, _) = &mut n.inputs[i];
+                *w += delta;
+            }
+
+            if rng.gen::<f32>() <= rate {
+                // reassign a neuron's activation function
+                let (n, _) = self.rand_neuron(rng);
+                n.write().unwrap().activation = ActivationFn::random(rng);
             }
         }
     }
@@ -212,36 +416,119 @@ impl<const I: usize, const O: usize> DivisionReproduction for NeuralNetworkTopol
 }
 
 #[cfg(feature = "crossover")]
-impl CrossoverReproduction for NeuralNetworkTopology {
+impl<const I: usize, const O: usize> CrossoverReproduction for NeuralNetworkTopology<I, O> {
+    /// NEAT-style crossover. `self` is treated as the fitter parent: its structure
+    /// (and therefore all disjoint/excess genes) is inherited wholesale, while for
+    /// connections shared by both parents — matched by innovation number — the weight
+    /// is picked at random from either parent.
     fn spawn_child(&self, other: &Self, rng: &mut impl Rng) -> Self {
-        todo!();
+        // Collect the other parent's genes keyed by innovation number.
+        let mut other_genes: HashMap<usize, f32> = HashMap::new();
+        other.collect_genes(&mut other_genes);
+
+        // Start from the fitter parent so disjoint/excess genes come from `self`.
+        let child = self.clone();
+
+        for n in child
+            .input_layer
+            .iter()
+            .chain(child.hidden_layers.iter())
+            .chain(child.output_layer.iter())
+        {
+            let mut n = n.write().unwrap();
+            for (_, w, inv) in &mut n.inputs {
+                if let Some(&other_w) = other_genes.get(inv) {
+                    // matching gene: inherit the weight from either parent at random.
+                    if rng.gen::<bool>() {
+                        *w = other_w;
+                    }
+                }
+            }
+        }
+
+        child
     }
 }
 
 /// A stateless version of [`Neuron`][crate::Neuron].
 #[derive(Debug, Clone)]
 pub struct NeuronTopology {
-    /// The input locations and weights.
-    pub inputs: Vec<(NeuronLocation, f32)>,
+    /// The input locations, their weights, and the global innovation number of each connection.
+    pub inputs: Vec<(NeuronLocation, f32, usize)>,
 
     /// The neuron's bias.
     pub bias: f32,
+
+    /// The activation function applied to this neuron's weighted sum.
+    pub activation: ActivationFn,
 }
 
 impl NeuronTopology {
     /// Creates a new neuron with the given input locations.
-    pub fn new(inputs: Vec<NeuronLocation>, rng: &mut impl Rng) -> Self {
-        let inputs = inputs.into_iter().map(|i| (i, rng.gen::<f32>())).collect();
+    /// Every incoming connection is stamped with a fresh innovation number, its weight
+    /// and bias are drawn from `init`, and a random activation function is chosen.
+    pub fn new(inputs: Vec<NeuronLocation>, init: WeightInitializer, rng: &mut impl Rng) -> Self {
+        let fan_in = inputs.len();
+        let inputs = inputs
+            .into_iter()
+            .map(|i| (i, init.sample(rng, fan_in), next_innovation()))
+            .collect();
 
         Self {
             inputs,
-            bias: rng.gen(),
+            bias: init.sample(rng, fan_in.max(1)),
+            activation: ActivationFn::random(rng),
+        }
+    }
+}
+
+/// The nonlinearity applied to a neuron's weighted sum when it is evaluated.
+/// Each [`NeuronTopology`] carries its own, and mutation may reassign it, so
+/// topology evolution can discover appropriate activations rather than baking one in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActivationFn {
+    /// Logistic sigmoid, `1 / (1 + e^-x)`.
+    Sigmoid,
+
+    /// Hyperbolic tangent.
+    Tanh,
+
+    /// Rectified linear unit, `max(0, x)`.
+    ReLU,
+
+    /// Identity; leaves the input untouched.
+    Linear,
+}
+
+impl ActivationFn {
+    /// Every available activation function, in declaration order.
+    pub const ALL: [ActivationFn; 4] = [
+        ActivationFn::Sigmoid,
+        ActivationFn::Tanh,
+        ActivationFn::ReLU,
+        ActivationFn::Linear,
+    ];
+
+    /// Picks a random activation function.
+    pub fn random(rng: &mut impl Rng) -> Self {
+        Self::ALL[rng.gen_range(0..Self::ALL.len())]
+    }
+
+    /// Applies the activation function to `x`.
+    pub fn apply(&self, x: f32) -> f32 {
+        match self {
+            Self::Sigmoid => 1. / (1. + (-x).exp()),
+            Self::Tanh => x.tanh(),
+            Self::ReLU => x.max(0.),
+            Self::Linear => x,
         }
     }
 }
 
 /// A pseudo-pointer of sorts used to make structural conversions very fast and easy to write.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NeuronLocation {
     /// Points to a neuron in the input layer at contained index.
     Input(usize),